@@ -3,16 +3,29 @@
     clippy::nursery,
     clippy::missing_inline_in_public_items
 )]
+#![cfg_attr(not(feature = "std"), no_std)]
 //! 100-bit ID stored in a u128.
 //! 64-bits of randomness every 31.25 milliseconds.
 //! 36-bits for time component with an epoch of 2020-01-01
 //! should last until 2088-01-14T22:14:07
+//!
+//! Core id encoding/decoding and [`IdGen`] are `no_std`-compatible; bring
+//! your own [`RandSource`] and [`Clock`]. [`Id::new`], [`MonotonicGen`], and
+//! [`Id::system_time`] are convenience wrappers around [`std`] and require
+//! the `std` feature.
 
-use std::{
-    fmt::Display,
-    str::FromStr,
-    time::{Duration, SystemTime, UNIX_EPOCH},
-};
+use core::{fmt::Display, str::FromStr};
+#[cfg(feature = "std")]
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub mod generator;
+#[cfg(feature = "std")]
+mod monotonic;
+pub mod serde;
+
+pub use generator::{Clock, IdGen, RandSource};
+#[cfg(feature = "std")]
+pub use monotonic::MonotonicGen;
 
 const STR_LEN: usize = 22;
 const SEP_IDX: [usize; 2] = [6, 15];
@@ -20,6 +33,10 @@ const RND_BITS: usize = 64;
 const RND_MASK: u128 = (1 << RND_BITS) - 1;
 const ID_BITS: usize = 100;
 const ID_MASK: u128 = (1 << ID_BITS) - 1;
+const TIME_BITS: usize = ID_BITS - RND_BITS;
+const TIME_MASK: u128 = (1 << TIME_BITS) - 1;
+/// Width in bytes of the canonical fixed-width encoding (100 bits rounded up).
+const ID_BYTES: usize = 13;
 /// Seconds since Unix epoch for 2020-01-01T00:00:00Z.
 const SECOND_EPOCH: u128 = 1_577_836_800;
 const TIME_SHIFT: usize = 5;
@@ -27,6 +44,7 @@ const TIME_SHIFT: usize = 5;
 const CHARS_STR: &str = "0123456789abcdefghjkmnpqrstvwxyz";
 const CHARS: &[u8] = CHARS_STR.as_bytes();
 
+#[allow(clippy::cast_possible_truncation)]
 const DECODE_MAP: [i8; 256] = {
     let mut arr = [-1i8; 256];
     let mut i = 0;
@@ -40,7 +58,7 @@ const DECODE_MAP: [i8; 256] = {
 #[derive(thiserror::Error, Debug)]
 pub enum Error {
     #[error("could not encode id {0}: {1}")]
-    Encode(u64, std::fmt::Error),
+    Encode(u64, core::fmt::Error),
     #[error("IdStr full: tried to write {byte} @ {idx}")]
     IdStrFull { byte: u8, idx: usize },
     #[error("decoding error: invalid digit: {0}")]
@@ -50,28 +68,28 @@ pub enum Error {
 }
 
 /// Id is a STR_LEN-char representation of a 64-bit number.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Id(u128);
 
-const fn timestamp_from_unix_dur(dur: Duration) -> u128 {
-    ((dur.as_millis() - (SECOND_EPOCH * 1_000)) << TIME_SHIFT) / 1_000
+const fn timestamp_from_millis(millis: u128) -> u128 {
+    ((millis - (SECOND_EPOCH * 1_000)) << TIME_SHIFT) / 1_000
 }
 
 impl Id {
-    /// Creates a new [`Id`].
+    /// Creates a new [`Id`] using [`fastrand`] and [`SystemTime`].
+    ///
+    /// A convenience wrapper around `IdGen<FastRand, SystemClock>` for the
+    /// common std case; use [`IdGen`] directly to plug in a seeded RNG or a
+    /// fixed clock (e.g. for deterministic tests), or on `no_std` targets.
     ///
     /// # Panics
     ///
     /// Panics if now is somehow earlier than the unix epoch.
+    #[cfg(feature = "std")]
     #[must_use]
     #[inline]
     pub fn new() -> Self {
-        #[allow(clippy::cast_possible_truncation)]
-        let unix_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-        let time = timestamp_from_unix_dur(unix_time);
-        let rnd = fastrand::u128(..);
-        let x = (time << RND_BITS) | (rnd & RND_MASK);
-        Self(x)
+        generator::IdGen::default().generate()
     }
 
     #[must_use]
@@ -79,8 +97,69 @@ impl Id {
     pub const fn from_u128(x: u128) -> Self {
         Self(x & ((1 << ID_BITS) - 1))
     }
+
+    /// Encodes the id as 13 big-endian bytes (the top 4 bits of the leading
+    /// byte are always zero).
+    ///
+    /// Unlike a varint `u128`, this is fixed-size and sorts bytewise in the
+    /// same order as the numeric value and the string form, at 13 bytes
+    /// instead of up to 19 for the LEB128 encoding of large values.
+    #[must_use]
+    #[inline]
+    pub const fn to_be_bytes(self) -> [u8; ID_BYTES] {
+        let full = (self.0 & ID_MASK).to_be_bytes();
+        let mut out = [0u8; ID_BYTES];
+        let mut i = 0;
+        while i < ID_BYTES {
+            out[i] = full[i + (16 - ID_BYTES)];
+            i += 1;
+        }
+        out
+    }
+
+    /// Reconstructs an [`Id`] from the 13-byte big-endian encoding produced
+    /// by [`Id::to_be_bytes`].
+    #[must_use]
+    #[inline]
+    pub const fn from_be_bytes(bytes: [u8; ID_BYTES]) -> Self {
+        let mut full = [0u8; 16];
+        let mut i = 0;
+        while i < ID_BYTES {
+            full[i + (16 - ID_BYTES)] = bytes[i];
+            i += 1;
+        }
+        Self(u128::from_be_bytes(full) & ID_MASK)
+    }
+
+    /// Returns the creation time embedded in this id, in milliseconds since
+    /// the Unix epoch.
+    #[must_use]
+    #[inline]
+    pub const fn timestamp_millis(&self) -> u128 {
+        let time = (self.0 >> RND_BITS) & TIME_MASK;
+        ((time * 1_000) >> TIME_SHIFT) + SECOND_EPOCH * 1_000
+    }
+
+    /// Returns the creation time embedded in this id as a [`SystemTime`].
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn system_time(&self) -> SystemTime {
+        #[allow(clippy::cast_possible_truncation)]
+        let millis = self.timestamp_millis() as u64;
+        UNIX_EPOCH + Duration::from_millis(millis)
+    }
+
+    /// Returns the embedded 64-bit random component.
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    pub const fn random_bits(&self) -> u64 {
+        (self.0 & RND_MASK) as u64
+    }
 }
 
+#[cfg(feature = "std")]
 impl Default for Id {
     #[inline]
     fn default() -> Self {
@@ -99,9 +178,9 @@ impl FromStr for Id {
 
 impl Display for Id {
     #[inline]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         let arr = encode_array(self.0);
-        let s = unsafe { std::str::from_utf8_unchecked(&arr[..]) };
+        let s = unsafe { core::str::from_utf8_unchecked(&arr[..]) };
         f.write_str(s)
     }
 }
@@ -198,6 +277,12 @@ const _: () = {
     assert!(result::unwrap_or!(decode(b"000000-0fzzzzzz-zzzzzz"), 0) == 0xFFFF_FFFF_FFFF_FFFF);
     assert!(result::unwrap_or!(decode(b"z00000-00000000-000000"), 0) == 0b11111 << 95);
     assert!(result::unwrap_or!(decode(b"zzzzzz-zzzzzzzz-zzzzzz"), 0) == (1 << 100) - 1);
+
+    // fixed-width big-endian encoding
+    assert!(Id::from_u128(0).to_be_bytes()[0] == 0);
+    assert!(Id::from_u128((1 << 100) - 1).to_be_bytes()[0] == 0b0000_1111);
+    let roundtrip = Id::from_be_bytes(Id::from_u128(0xdead_beef_beef_dead).to_be_bytes());
+    assert!(roundtrip.0 == 0xdead_beef_beef_dead);
 };
 
 #[cfg(test)]
@@ -216,6 +301,7 @@ mod test {
         assert_eq!(id.0, 0xFFFF_FFFF_FFFF_FFFF);
     }
 
+    #[cfg(feature = "std")]
     #[test]
     fn is_ok() {
         let id = Id::from(0xdead_beef_beef_dead);
@@ -226,13 +312,85 @@ mod test {
 
         let id = Id::from_str("000000-0dxbdyxy-zezqnd").unwrap();
         assert_eq!(id.0, 0xdead_beef_beef_dead);
+    }
 
+    #[cfg(feature = "std")]
+    #[test]
+    fn new_round_trips_through_display_and_from_str() {
         let id = Id::new();
         let s = id.to_string();
         assert_eq!(s, Id::from_str(&s).unwrap().to_string());
         assert_eq!(id, Id::from_str(&s).unwrap());
     }
 
+    #[test]
+    fn to_be_bytes_round_trips() {
+        let id = Id::from_u128(0xdead_beef_beef_dead);
+        let bytes = id.to_be_bytes();
+        assert_eq!(bytes.len(), 13);
+        assert_eq!(Id::from_be_bytes(bytes), id);
+    }
+
+    #[test]
+    fn to_be_bytes_sorts_like_the_numeric_value() {
+        let low = Id::from_u128(1);
+        let high = Id::from_u128(2);
+        assert!(low.to_be_bytes() < high.to_be_bytes());
+    }
+
+    #[test]
+    fn ord_matches_numeric_order() {
+        let low = Id::from_u128(1);
+        let high = Id::from_u128(2);
+        assert!(low < high);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn ord_matches_string_order() {
+        let low = Id::from_u128(1);
+        let high = Id::from_u128(2);
+        assert!(low.to_string() < high.to_string());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn timestamp_millis_round_trips_through_new() {
+        let before = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let id = Id::new();
+        let after = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis();
+        let millis = id.timestamp_millis();
+        // `timestamp_millis` recovers the 31.25ms slot floor, which can be
+        // up to one tick below the instant `Id::new` actually ran at.
+        assert!(before <= millis + 32 && millis <= after);
+        #[allow(clippy::cast_possible_truncation)]
+        let millis_u64 = millis as u64;
+        assert_eq!(id.system_time(), UNIX_EPOCH + Duration::from_millis(millis_u64));
+    }
+
+    #[test]
+    fn random_bits_matches_low_64_bits() {
+        let id = Id::from_u128(0xdead_beef_beef_dead);
+        assert_eq!(id.random_bits(), 0xdead_beef_beef_dead);
+
+        let id = Id::from_u128(1 << 99);
+        assert_eq!(id.random_bits(), 0);
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn be_bytes_round_trip(x in 0u128..) {
+            let id = Id::from_u128(x);
+            assert_eq!(Id::from_be_bytes(id.to_be_bytes()), id);
+        }
+    }
+
     const PATTERN: &str = konst::string::str_concat!(&[
         "[", CHARS_STR, "]{6}-[", CHARS_STR, "]{8}-[", CHARS_STR, "]{6}"
     ]);
@@ -247,7 +405,10 @@ mod test {
         fn parses_valid_ids(s in PATTERN) {
             Id::from_str(&s).unwrap();
         }
+    }
 
+    #[cfg(feature = "std")]
+    proptest::proptest! {
         #[test]
         fn encodes_u128s(x in 0u128..) {
             let encoded_id = Id::from_u128(x);
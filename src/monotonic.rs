@@ -0,0 +1,190 @@
+//! Monotonic [`Id`] generation within a single generator.
+
+use std::sync::Mutex;
+
+use crate::{
+    generator::{Clock, FastRand, RandSource, SystemClock},
+    timestamp_from_millis, Id, RND_BITS, RND_MASK, TIME_MASK,
+};
+
+/// Generates [`Id`]s that are strictly increasing across successive calls on
+/// the same generator, even within the same 31.25 ms time slot, until the
+/// largest representable id is reached (see [`Id::new_monotonic`]).
+///
+/// Mirrors ULID's monotonic mode: if two calls land in the same tick, the
+/// second reuses the first's time field and increments its random
+/// component by one instead of drawing a fresh one, so the string and
+/// big-endian forms stay creation-ordered. Parameterized over a
+/// [`RandSource`] and [`Clock`] like [`IdGen`](crate::IdGen), so tests can
+/// plug in a seeded RNG and a fixed clock instead of depending on the real
+/// wall clock.
+pub struct MonotonicGen<R, C> {
+    state: Mutex<(u128, R)>,
+    clock: C,
+}
+
+impl<R: RandSource, C: Clock> MonotonicGen<R, C> {
+    /// Creates a new [`MonotonicGen`] from the given randomness and clock
+    /// sources, with no prior emitted id.
+    #[must_use]
+    #[inline]
+    pub const fn new(rand: R, clock: C) -> Self {
+        Self {
+            state: Mutex::new((0, rand)),
+            clock,
+        }
+    }
+}
+
+/// A [`MonotonicGen`] backed by the global [`fastrand`] generator and
+/// [`std::time::SystemTime::now`].
+impl Default for MonotonicGen<FastRand, SystemClock> {
+    #[inline]
+    fn default() -> Self {
+        Self::new(FastRand, SystemClock)
+    }
+}
+
+impl Id {
+    /// Creates a new [`Id`] that is strictly greater than the last id `gen`
+    /// produced, even if called again within the same 31.25 ms time slot.
+    /// The one exception is saturation at the largest id, described below.
+    ///
+    /// When two calls land in the same tick, this reuses the stored time
+    /// field and increments the stored random component by one, carrying
+    /// into the time field on random overflow. A clock that goes backwards
+    /// is treated the same as landing in the same tick, so the output is
+    /// never smaller than the last one this generator emitted. If the time
+    /// field is already at its maximum when a carry is needed, the output
+    /// saturates at the largest representable id instead of wrapping
+    /// around to a smaller one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `gen`'s internal lock is poisoned.
+    #[must_use]
+    #[inline]
+    #[allow(clippy::significant_drop_tightening)]
+    pub fn new_monotonic<R: RandSource, C: Clock>(gen: &MonotonicGen<R, C>) -> Self {
+        let time = timestamp_from_millis(gen.clock.now_millis());
+
+        let value = {
+            let mut state = gen.state.lock().unwrap();
+            let (last, entropy) = &mut *state;
+            let last_time = (*last >> RND_BITS) & TIME_MASK;
+
+            let value = if time > last_time {
+                let fresh = entropy.next_u64();
+                (time << RND_BITS) | u128::from(fresh)
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let last_rnd = (*last & RND_MASK) as u64;
+                last_rnd.checked_add(1).map_or_else(
+                    || {
+                        let next_time = last_time + 1;
+                        if next_time > TIME_MASK {
+                            // Time field is also exhausted: clamp to the
+                            // largest representable id instead of wrapping
+                            // bit 100 back around to 0, which would emit a
+                            // smaller id.
+                            (TIME_MASK << RND_BITS) | RND_MASK
+                        } else {
+                            next_time << RND_BITS
+                        }
+                    },
+                    |carried| (last_time << RND_BITS) | u128::from(carried),
+                )
+            };
+
+            *last = value;
+            value
+        };
+
+        Self::from_u128(value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    struct CountingRand(u64);
+
+    impl RandSource for CountingRand {
+        fn next_u64(&mut self) -> u64 {
+            self.0 += 1;
+            self.0
+        }
+    }
+
+    struct FixedClock(u128);
+
+    impl Clock for FixedClock {
+        fn now_millis(&self) -> u128 {
+            self.0
+        }
+    }
+
+    /// Builds a generator seeded with `last` and a clock fixed at
+    /// `clock_millis`, so tests exercising the same-tick/carry branch don't
+    /// depend on how `last`'s time field compares to the real wall clock.
+    fn seeded(last: u128, clock_millis: u128) -> MonotonicGen<CountingRand, FixedClock> {
+        let gen = MonotonicGen::new(CountingRand(0), FixedClock(clock_millis));
+        *gen.state.lock().unwrap() = (last, CountingRand(0));
+        gen
+    }
+
+    #[test]
+    fn successive_ids_strictly_increase() {
+        let gen = MonotonicGen::default();
+        let mut prev = Id::new_monotonic(&gen);
+        for _ in 0..1000 {
+            let next = Id::new_monotonic(&gen);
+            assert!(next > prev);
+            prev = next;
+        }
+    }
+
+    #[test]
+    fn same_tick_increments_random_component() {
+        // Clock fixed at the epoch so its time field (0) is never greater
+        // than the seeded `last_time` (5), forcing the same-tick branch.
+        let gen = seeded((5u128 << RND_BITS) | 0xa, crate::SECOND_EPOCH * 1_000);
+        let id = Id::new_monotonic(&gen);
+        let last = gen.state.lock().unwrap().0;
+        assert_eq!(last >> RND_BITS, 5);
+        assert_eq!(last & RND_MASK, 0xb);
+        assert_eq!(id, Id::from_u128(last));
+    }
+
+    #[test]
+    fn random_overflow_carries_into_time_field() {
+        let gen = seeded((5u128 << RND_BITS) | RND_MASK, crate::SECOND_EPOCH * 1_000);
+        let id = Id::new_monotonic(&gen);
+        let last = gen.state.lock().unwrap().0;
+        assert_eq!(last >> RND_BITS, 6);
+        assert_eq!(last & RND_MASK, 0);
+        assert_eq!(id, Id::from_u128(last));
+    }
+
+    #[test]
+    fn overflow_at_max_time_saturates_instead_of_wrapping() {
+        let max_value = (TIME_MASK << RND_BITS) | RND_MASK;
+        let gen = seeded(max_value, crate::SECOND_EPOCH * 1_000);
+        let id = Id::new_monotonic(&gen);
+        let last = gen.state.lock().unwrap().0;
+        // Must clamp at the all-ones id, not wrap bit 100 back around to 0.
+        assert_eq!(last, max_value);
+        assert_eq!(id, Id::from_u128(max_value));
+        assert!(id >= Id::from_u128(max_value));
+    }
+
+    #[test]
+    fn backwards_clock_never_emits_a_smaller_id() {
+        let gen = MonotonicGen::default();
+        let future_time = TIME_MASK;
+        *gen.state.lock().unwrap() = ((future_time << RND_BITS) | 1, FastRand);
+        let id = Id::new_monotonic(&gen);
+        assert!(id > Id::from_u128((future_time << RND_BITS) | 1));
+    }
+}
@@ -0,0 +1,66 @@
+//! Forces the 13-byte big-endian representation regardless of format.
+
+use serde::de::{SeqAccess, Visitor};
+
+use crate::Id;
+
+/// Serializes an [`Id`] as 13 big-endian bytes.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+#[inline]
+pub fn serialize<S>(id: &Id, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_bytes(&id.to_be_bytes())
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Id;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("13 big-endian bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let bytes: [u8; 13] = v.try_into().map_err(|_| E::invalid_length(v.len(), &self))?;
+        Ok(Id::from_be_bytes(bytes))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = [0u8; 13];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(serde::de::Error::invalid_length(14, &self));
+        }
+        Ok(Id::from_be_bytes(bytes))
+    }
+}
+
+/// Deserializes an [`Id`] from 13 big-endian bytes.
+///
+/// # Errors
+///
+/// Returns an error if the deserializer fails, or the input isn't exactly
+/// 13 bytes.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Id, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(BytesVisitor)
+}
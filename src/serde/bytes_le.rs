@@ -0,0 +1,92 @@
+//! Forces the 13-byte little-endian representation regardless of format.
+
+use serde::de::{SeqAccess, Visitor};
+
+use crate::{Id, ID_MASK};
+
+/// Serializes an [`Id`] as 13 little-endian bytes.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+#[inline]
+pub fn serialize<S>(id: &Id, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let full = (id.0 & ID_MASK).to_le_bytes();
+    serializer.serialize_bytes(&full[..13])
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Id;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("13 little-endian bytes")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let arr: [u8; 13] = v.try_into().map_err(|_| E::invalid_length(v.len(), &self))?;
+        let mut full = [0u8; 16];
+        full[..13].copy_from_slice(&arr);
+        Ok(Id::from_u128(u128::from_le_bytes(full)))
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut full = [0u8; 16];
+        for (i, byte) in full[..13].iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
+        }
+        if seq.next_element::<u8>()?.is_some() {
+            return Err(serde::de::Error::invalid_length(14, &self));
+        }
+        Ok(Id::from_u128(u128::from_le_bytes(full)))
+    }
+}
+
+/// Deserializes an [`Id`] from 13 little-endian bytes.
+///
+/// # Errors
+///
+/// Returns an error if the deserializer fails, or the input isn't exactly
+/// 13 bytes.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Id, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(BytesVisitor)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn to_le_bytes(id: &Id) -> [u8; 13] {
+        (id.0 & ID_MASK).to_le_bytes()[..13].try_into().unwrap()
+    }
+
+    fn from_le_bytes(bytes: [u8; 13]) -> Id {
+        let mut full = [0u8; 16];
+        full[..13].copy_from_slice(&bytes);
+        Id::from_u128(u128::from_le_bytes(full))
+    }
+
+    proptest::proptest! {
+        #[test]
+        fn le_bytes_round_trip(x in 0u128..) {
+            let id = Id::from_u128(x);
+            assert_eq!(from_le_bytes(to_le_bytes(&id)), id);
+        }
+    }
+}
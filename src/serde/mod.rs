@@ -0,0 +1,245 @@
+//! Alternative wire representations for use with `#[serde(with = "...")]`.
+//!
+//! The default [`Id`] impls below switch between the 22-char Crockford
+//! string and the 13-byte big-endian encoding ([`Id::to_be_bytes`]) based
+//! on `is_human_readable()`. These modules
+//! let a field pin down one specific representation regardless of format,
+//! mirroring the `decimal`/`prefixed`/`bytes::{be,le}` modules `ethnum`
+//! exposes for its integer types:
+//!
+//! ```ignore
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Event {
+//!     #[serde(with = "ident::serde::string")]
+//!     id: Id,
+//! }
+//! ```
+
+#[cfg(feature = "serde")]
+use ::serde::de::Visitor;
+
+#[cfg(feature = "serde")]
+use crate::{decode, encode_array, Id};
+
+#[cfg(feature = "serde")]
+pub mod bytes_be;
+#[cfg(feature = "serde")]
+pub mod bytes_le;
+#[cfg(feature = "serde")]
+pub mod string;
+#[cfg(feature = "serde")]
+pub mod u128;
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Id {
+    #[inline]
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            let arr = encode_array(self.0);
+            let s = unsafe { core::str::from_utf8_unchecked(&arr[..]) };
+            serializer.serialize_str(s)
+        } else {
+            serializer.serialize_bytes(&self.to_be_bytes())
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+struct IdVisitor;
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for IdVisitor {
+    type Value = Id;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("&str or 13 big-endian bytes")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Id::from_u128(
+            decode(v.as_bytes()).map_err(|e| serde::de::Error::custom(e))?,
+        ))
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        let bytes: [u8; 13] = v
+            .try_into()
+            .map_err(|_| serde::de::Error::invalid_length(v.len(), &self))?;
+        Ok(Id::from_be_bytes(bytes))
+    }
+
+    fn visit_u128<E>(self, v: u128) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Id::from_u128(v))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Id {
+    #[inline]
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(IdVisitor)
+        } else {
+            deserializer.deserialize_bytes(IdVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod test_serde {
+    use super::*;
+    use assert2::assert;
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct TestStruct {
+        a: u8,
+        id: Id,
+        b: u8,
+    }
+
+    #[test]
+    fn serializes_to_string_if_human_read_fmt() {
+        let x = TestStruct {
+            id: Id::from_u128(32),
+            a: 1,
+            b: 2,
+        };
+        let result = serde_json::to_string(&x).unwrap();
+        assert!(result == "{\"a\":1,\"id\":\"000000-00000000-000010\",\"b\":2}");
+    }
+
+    #[test]
+    fn serializes_to_be_bytes_if_not_human_read_fmt() {
+        let x = TestStruct {
+            id: Id::from_u128(1 << 32),
+            a: 1,
+            b: 2,
+        };
+        let mut buf = [0u8; 128];
+        let result = postcard::to_slice(&x, &mut buf).unwrap();
+        assert!(result == [1, 13, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2]);
+        let result: TestStruct = postcard::from_bytes(result).unwrap();
+        assert!(result.id == Id::from_u128(1 << 32));
+    }
+
+    #[test]
+    fn deserializes_from_be_bytes_if_not_human_read_fmt() {
+        let bytes = [1, 13, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 2];
+        let result: TestStruct = postcard::from_bytes(&bytes[..]).unwrap();
+        assert!(result.id == Id::from_u128(1 << 32));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "serde")]
+mod test_with {
+    use assert2::assert;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct StringField {
+        #[serde(with = "crate::serde::string")]
+        id: Id,
+    }
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct U128Field {
+        #[serde(with = "crate::serde::u128")]
+        id: Id,
+    }
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct BytesBeField {
+        #[serde(with = "crate::serde::bytes_be")]
+        id: Id,
+    }
+
+    #[derive(Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    struct BytesLeField {
+        #[serde(with = "crate::serde::bytes_le")]
+        id: Id,
+    }
+
+    #[test]
+    fn string_forces_string_even_in_postcard() {
+        let x = StringField {
+            id: Id::from_u128(1 << 32),
+        };
+        let mut buf = [0u8; 128];
+        let encoded = postcard::to_slice(&x, &mut buf).unwrap();
+        assert!(encoded[0] == 22);
+        assert!(&encoded[1..] == b"000000-00000004-000000");
+        let decoded: StringField = postcard::from_bytes(encoded).unwrap();
+        assert!(decoded == x);
+    }
+
+    #[test]
+    fn u128_forces_number_even_in_json() {
+        let x = U128Field {
+            id: Id::from_u128(32),
+        };
+        let result = serde_json::to_string(&x).unwrap();
+        assert!(result == "{\"id\":32}");
+        let decoded: U128Field = serde_json::from_str(&result).unwrap();
+        assert!(decoded == x);
+    }
+
+    #[test]
+    fn bytes_be_round_trips_through_postcard() {
+        let x = BytesBeField {
+            id: Id::from_u128(0xdead_beef_beef_dead),
+        };
+        let mut buf = [0u8; 128];
+        let encoded = postcard::to_slice(&x, &mut buf).unwrap();
+        let decoded: BytesBeField = postcard::from_bytes(encoded).unwrap();
+        assert!(decoded == x);
+    }
+
+    #[test]
+    fn bytes_le_round_trips_through_postcard() {
+        let x = BytesLeField {
+            id: Id::from_u128(0xdead_beef_beef_dead),
+        };
+        let mut buf = [0u8; 128];
+        let encoded = postcard::to_slice(&x, &mut buf).unwrap();
+        let decoded: BytesLeField = postcard::from_bytes(encoded).unwrap();
+        assert!(decoded == x);
+    }
+
+    #[test]
+    fn bytes_be_round_trips_through_json() {
+        let x = BytesBeField {
+            id: Id::from_u128(0xdead_beef_beef_dead),
+        };
+        let encoded = serde_json::to_string(&x).unwrap();
+        let decoded: BytesBeField = serde_json::from_str(&encoded).unwrap();
+        assert!(decoded == x);
+    }
+
+    #[test]
+    fn bytes_le_round_trips_through_json() {
+        let x = BytesLeField {
+            id: Id::from_u128(0xdead_beef_beef_dead),
+        };
+        let encoded = serde_json::to_string(&x).unwrap();
+        let decoded: BytesLeField = serde_json::from_str(&encoded).unwrap();
+        assert!(decoded == x);
+    }
+}
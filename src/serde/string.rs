@@ -0,0 +1,53 @@
+//! Forces the 22-char Crockford string representation regardless of format.
+
+use serde::de::Visitor;
+
+use crate::{decode, encode_array, Id};
+
+/// Serializes an [`Id`] as its 22-char Crockford string.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+#[inline]
+pub fn serialize<S>(id: &Id, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    let arr = encode_array(id.0);
+    let s = unsafe { core::str::from_utf8_unchecked(&arr[..]) };
+    serializer.serialize_str(s)
+}
+
+struct StrVisitor;
+
+impl Visitor<'_> for StrVisitor {
+    type Value = Id;
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        formatter.write_str("a 22-char Crockford-encoded id")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(Id::from_u128(
+            decode(v.as_bytes()).map_err(serde::de::Error::custom)?,
+        ))
+    }
+}
+
+/// Deserializes an [`Id`] from its 22-char Crockford string.
+///
+/// # Errors
+///
+/// Returns an error if the deserializer fails, or the input isn't a valid
+/// 22-char Crockford-encoded id.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Id, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    deserializer.deserialize_str(StrVisitor)
+}
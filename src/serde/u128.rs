@@ -0,0 +1,29 @@
+//! Forces the raw `u128` representation regardless of format.
+
+use crate::Id;
+
+/// Serializes an [`Id`] as its underlying `u128`.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails.
+#[inline]
+pub fn serialize<S>(id: &Id, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.serialize_u128(id.0)
+}
+
+/// Deserializes an [`Id`] from a `u128`.
+///
+/// # Errors
+///
+/// Returns an error if the deserializer fails.
+#[inline]
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Id, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    <u128 as serde::Deserialize>::deserialize(deserializer).map(Id::from_u128)
+}